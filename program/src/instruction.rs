@@ -471,6 +471,9 @@ impl AmmInstruction {
             5 => Self::MigrateToOpenBook,
             6 => {
                 let (param, rest) = Self::unpack_u8(rest)?;
+                if param as u64 > AmmParams::UpdateOpenOrder.into_u64() {
+                    return Err(ProgramError::InvalidInstructionData.into());
+                }
                 match AmmParams::from_u64(param as u64) {
                     AmmParams::AmmOwner => {
                         if rest.len() >= 32 {
@@ -562,6 +565,9 @@ impl AmmInstruction {
             }
             12 => {
                 let (param, rest) = Self::unpack_u8(rest)?;
+                if param as u64 > SimulateParams::RunCrankInfo.into_u64() {
+                    return Err(ProgramError::InvalidInstructionData.into());
+                }
                 match SimulateParams::from_u64(param as u64) {
                     SimulateParams::PoolInfo | SimulateParams::RunCrankInfo => {
                         Self::SimulateInfo(SimulateInstruction {
@@ -607,12 +613,16 @@ impl AmmInstruction {
                 let (param, rest) = Self::unpack_u8(rest)?;
                 match param {
                     0 | 1 => {
-                        let pubkey = array_ref![rest, 0, 32];
-                        Self::UpdateConfigAccount(ConfigArgs {
-                            param,
-                            owner: Some(Pubkey::new_from_array(*pubkey)),
-                            create_pool_fee: None,
-                        })
+                        if rest.len() >= 32 {
+                            let pubkey = array_ref![rest, 0, 32];
+                            Self::UpdateConfigAccount(ConfigArgs {
+                                param,
+                                owner: Some(Pubkey::new_from_array(*pubkey)),
+                                create_pool_fee: None,
+                            })
+                        } else {
+                            return Err(ProgramError::InvalidInstructionData.into());
+                        }
                     }
                     2 => {
                         let (create_pool_fee, _rest) = Self::unpack_u64(rest)?;
@@ -628,6 +638,9 @@ impl AmmInstruction {
                 }
             }
             16 => {
+                if rest.len() < 32 {
+                    return Err(ProgramError::InvalidInstructionData.into());
+                }
                 let (orders_hash_bytes, _rest) = rest.split_at(32);
                 let mut orders_hash = [0u8; 32];
                 orders_hash.copy_from_slice(orders_hash_bytes);
@@ -1238,6 +1251,136 @@ pub fn swap_base_out(
     })
 }
 
+/// Creates a 'swap base in seq' instruction.
+pub fn swap_base_in_seq(
+    amm_program: &Pubkey,
+    sequencer_orders: &Pubkey,
+    amm_pool: &Pubkey,
+    amm_authority: &Pubkey,
+    amm_open_orders: &Pubkey,
+    amm_coin_vault: &Pubkey,
+    amm_pc_vault: &Pubkey,
+    market_program: &Pubkey,
+    market: &Pubkey,
+    market_bids: &Pubkey,
+    market_asks: &Pubkey,
+    market_event_queue: &Pubkey,
+    market_coin_vault: &Pubkey,
+    market_pc_vault: &Pubkey,
+    market_vault_signer: &Pubkey,
+    user_token_source: &Pubkey,
+    user_token_destination: &Pubkey,
+    user_source_owner: &Pubkey,
+
+    amount_in: u64,
+    minimum_amount_out: u64,
+    order_index: u64,
+) -> Result<Instruction, ProgramError> {
+    let data = AmmInstruction::SwapBaseInSeq(SwapSequencedInstructionBaseIn {
+        amount_in,
+        minimum_amount_out,
+        order_index,
+    })
+    .pack()?;
+
+    let accounts = vec![
+        // sequencer
+        AccountMeta::new(*sequencer_orders, false),
+        // spl token
+        AccountMeta::new_readonly(spl_token::id(), false),
+        // amm
+        AccountMeta::new(*amm_pool, false),
+        AccountMeta::new_readonly(*amm_authority, false),
+        AccountMeta::new(*amm_open_orders, false),
+        AccountMeta::new(*amm_coin_vault, false),
+        AccountMeta::new(*amm_pc_vault, false),
+        // market
+        AccountMeta::new_readonly(*market_program, false),
+        AccountMeta::new(*market, false),
+        AccountMeta::new(*market_bids, false),
+        AccountMeta::new(*market_asks, false),
+        AccountMeta::new(*market_event_queue, false),
+        AccountMeta::new(*market_coin_vault, false),
+        AccountMeta::new(*market_pc_vault, false),
+        AccountMeta::new_readonly(*market_vault_signer, false),
+        // user
+        AccountMeta::new(*user_token_source, false),
+        AccountMeta::new(*user_token_destination, false),
+        AccountMeta::new_readonly(*user_source_owner, true),
+    ];
+
+    Ok(Instruction {
+        program_id: *amm_program,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a 'swap base out seq' instruction.
+pub fn swap_base_out_seq(
+    amm_program: &Pubkey,
+    sequencer_orders: &Pubkey,
+    amm_pool: &Pubkey,
+    amm_authority: &Pubkey,
+    amm_open_orders: &Pubkey,
+    amm_coin_vault: &Pubkey,
+    amm_pc_vault: &Pubkey,
+    market_program: &Pubkey,
+    market: &Pubkey,
+    market_bids: &Pubkey,
+    market_asks: &Pubkey,
+    market_event_queue: &Pubkey,
+    market_coin_vault: &Pubkey,
+    market_pc_vault: &Pubkey,
+    market_vault_signer: &Pubkey,
+    user_token_source: &Pubkey,
+    user_token_destination: &Pubkey,
+    user_source_owner: &Pubkey,
+
+    max_amount_in: u64,
+    amount_out: u64,
+    order_index: u64,
+) -> Result<Instruction, ProgramError> {
+    let data = AmmInstruction::SwapBaseOutSeq(SwapSequencedInstructionBaseOut {
+        max_amount_in,
+        amount_out,
+        order_index,
+    })
+    .pack()?;
+
+    let accounts = vec![
+        // sequencer
+        AccountMeta::new(*sequencer_orders, false),
+        // spl token
+        AccountMeta::new_readonly(spl_token::id(), false),
+        // amm
+        AccountMeta::new(*amm_pool, false),
+        AccountMeta::new_readonly(*amm_authority, false),
+        AccountMeta::new(*amm_open_orders, false),
+        AccountMeta::new(*amm_coin_vault, false),
+        AccountMeta::new(*amm_pc_vault, false),
+        // market
+        AccountMeta::new_readonly(*market_program, false),
+        AccountMeta::new(*market, false),
+        AccountMeta::new(*market_bids, false),
+        AccountMeta::new(*market_asks, false),
+        AccountMeta::new(*market_event_queue, false),
+        AccountMeta::new(*market_coin_vault, false),
+        AccountMeta::new(*market_pc_vault, false),
+        AccountMeta::new_readonly(*market_vault_signer, false),
+        // user
+        AccountMeta::new(*user_token_source, false),
+        AccountMeta::new(*user_token_destination, false),
+        AccountMeta::new_readonly(*user_source_owner, true),
+    ];
+
+    Ok(Instruction {
+        program_id: *amm_program,
+        accounts,
+        data,
+    })
+}
+
 /// Creates a 'migrate_to_openbook' instruction.
 pub fn migrate_to_openbook(
     amm_program: &Pubkey,
@@ -1826,3 +1969,157 @@ pub fn update_config_account(
         data,
     })
 }
+
+/// Creates a 'submit_sequencer_orders' instruction.
+pub fn submit_sequencer_orders(
+    amm_program: &Pubkey,
+    sequencer_orders: &Pubkey,
+    sequencer: &Pubkey,
+    orders_hash: [u8; 32],
+) -> Result<Instruction, ProgramError> {
+    let data =
+        AmmInstruction::SubmitSequencerOrders(SubmitSequencerOrdersInstruction { orders_hash })
+            .pack()?;
+    let accounts = vec![
+        AccountMeta::new(*sequencer_orders, false),
+        AccountMeta::new_readonly(*sequencer, true),
+    ];
+    Ok(Instruction {
+        program_id: *amm_program,
+        accounts,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_pack_unpack_sequenced_swaps() {
+        let instructions = [
+            AmmInstruction::SubmitSequencerOrders(SubmitSequencerOrdersInstruction {
+                orders_hash: [7u8; 32],
+            }),
+            AmmInstruction::SwapBaseInSeq(SwapSequencedInstructionBaseIn {
+                amount_in: 1_000_000,
+                minimum_amount_out: 990_000,
+                order_index: 42,
+            }),
+            AmmInstruction::SwapBaseOutSeq(SwapSequencedInstructionBaseOut {
+                max_amount_in: u64::MAX,
+                amount_out: 1,
+                order_index: u64::MAX,
+            }),
+        ];
+        for instruction in instructions.iter() {
+            let data = instruction.pack().unwrap();
+            assert_eq!(AmmInstruction::unpack(&data).unwrap(), *instruction);
+        }
+    }
+
+    #[test]
+    fn test_sequenced_swap_layout() {
+        let data = AmmInstruction::SwapBaseInSeq(SwapSequencedInstructionBaseIn {
+            amount_in: 1,
+            minimum_amount_out: 2,
+            order_index: 3,
+        })
+        .pack()
+        .unwrap();
+        let mut expected = vec![17u8];
+        expected.extend_from_slice(&1u64.to_le_bytes());
+        expected.extend_from_slice(&2u64.to_le_bytes());
+        expected.extend_from_slice(&3u64.to_le_bytes());
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn test_swap_base_in_seq_instruction() {
+        let keys: Vec<Pubkey> = (0..18).map(|_| Pubkey::new_unique()).collect();
+        let swap = swap_base_in(
+            &keys[0], &keys[2], &keys[3], &keys[4], &keys[5], &keys[6], &keys[7], &keys[8],
+            &keys[9], &keys[10], &keys[11], &keys[12], &keys[13], &keys[14], &keys[15], &keys[16],
+            &keys[17], 100, 90,
+        )
+        .unwrap();
+        let swap_seq = swap_base_in_seq(
+            &keys[0], &keys[1], &keys[2], &keys[3], &keys[4], &keys[5], &keys[6], &keys[7],
+            &keys[8], &keys[9], &keys[10], &keys[11], &keys[12], &keys[13], &keys[14], &keys[15],
+            &keys[16], &keys[17], 100, 90, 5,
+        )
+        .unwrap();
+
+        // same accounts as the plain swap, with the sequencer orders account first
+        assert_eq!(swap_seq.program_id, keys[0]);
+        assert_eq!(swap_seq.accounts[0], AccountMeta::new(keys[1], false));
+        assert_eq!(swap_seq.accounts[1..], swap.accounts[..]);
+
+        let mut data = vec![17u8];
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&90u64.to_le_bytes());
+        data.extend_from_slice(&5u64.to_le_bytes());
+        assert_eq!(swap_seq.data, data);
+    }
+
+    #[test]
+    fn test_submit_sequencer_orders_instruction() {
+        let program = Pubkey::new_unique();
+        let orders = Pubkey::new_unique();
+        let sequencer = Pubkey::new_unique();
+        let instruction =
+            submit_sequencer_orders(&program, &orders, &sequencer, [9u8; 32]).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(orders, false),
+                AccountMeta::new_readonly(sequencer, true),
+            ]
+        );
+        let mut data = vec![16u8];
+        data.extend_from_slice(&[9u8; 32]);
+        assert_eq!(instruction.data, data);
+    }
+
+    #[test]
+    fn test_unpack_short_data() {
+        let inputs: [&[u8]; 7] = [
+            &[],
+            &[16, 1, 2, 3],
+            &[15, 0, 1, 2],
+            &[15, 1],
+            &[6, 200, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[12, 9],
+            &[17, 1, 0, 0, 0, 0, 0, 0, 0],
+        ];
+        for input in inputs.iter() {
+            assert_eq!(
+                AmmInstruction::unpack(input),
+                Err(ProgramError::InvalidInstructionData)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_unpack_arbitrary_data(data in proptest::collection::vec(any::<u8>(), 0..128)) {
+            let _ = AmmInstruction::unpack(&data);
+        }
+
+        #[test]
+        fn test_unpack_arbitrary_payload(
+            tag in 0u8..=18,
+            rest in proptest::collection::vec(any::<u8>(), 0..80),
+        ) {
+            let mut data = vec![tag];
+            data.extend_from_slice(&rest);
+            if let Ok(instruction) = AmmInstruction::unpack(&data) {
+                // whatever parses must re-encode to a prefix of the input
+                if let Ok(packed) = instruction.pack() {
+                    prop_assert!(data.starts_with(&packed));
+                }
+            }
+        }
+    }
+}
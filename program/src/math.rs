@@ -452,6 +452,39 @@ impl Calculator {
         }
         return amount_in;
     }
+
+    /// Constant-product output for a base-in swap, rounded the same way as
+    /// `process_swap_base_in`. The swap fee uses `checked_ceil_div`, which rounds
+    /// up when the exact fee is at least 1 and to the nearest unit when it is
+    /// below 1; the output rounds down.
+    /// Pure math, so it can back off-chain quotes as well.
+    pub fn get_amount_out(
+        amount_in: u64,
+        reserve_in: u64,
+        reserve_out: u64,
+        fee_numerator: u64,
+        fee_denominator: u64,
+    ) -> Result<u64, AmmError> {
+        let swap_fee = U128::from(amount_in)
+            .checked_mul(fee_numerator.into())
+            .ok_or(AmmError::CheckedMulOverflow)?
+            .checked_ceil_div(fee_denominator.into())
+            .ok_or(AmmError::CheckedDivOverflow)?
+            .0;
+        let amount_in_after_fee = U128::from(amount_in)
+            .checked_sub(swap_fee)
+            .ok_or(AmmError::CheckedSubOverflow)?;
+        // amount_out = reserve_out * amount_in / (reserve_in + amount_in)
+        let denominator = U128::from(reserve_in)
+            .checked_add(amount_in_after_fee)
+            .ok_or(AmmError::CheckedAddOverflow)?;
+        let amount_out = U128::from(reserve_out)
+            .checked_mul(amount_in_after_fee)
+            .ok_or(AmmError::CheckedMulOverflow)?
+            .checked_div(denominator)
+            .ok_or(AmmError::CheckedDivOverflow)?;
+        Self::to_u64(amount_out.as_u128())
+    }
 }
 
 /// The invariant calculator.
@@ -654,3 +687,113 @@ impl CheckedCeilDiv for U128 {
         Some((quotient, rhs))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_get_amount_out() {
+        // same pool as processor::test::test_swap_base_in, coin -> pc
+        let amount_out =
+            Calculator::get_amount_out(212854295571, 77043918330755, 1511361338135, 25, 10000)
+                .unwrap();
+        assert_eq!(amount_out, 4153651556);
+    }
+
+    #[test]
+    fn test_get_amount_out_large_reserves() {
+        let amount_out =
+            Calculator::get_amount_out(u64::MAX, u64::MAX, u64::MAX, 25, 10000).unwrap();
+        assert_eq!(amount_out, 9211828392252955061);
+        assert!(amount_out < u64::MAX);
+    }
+
+    #[test]
+    fn test_get_amount_out_fee_near_max() {
+        // amount_in * fee_numerator needs the full u128 range
+        assert_eq!(
+            Calculator::get_amount_out(u64::MAX, u64::MAX, u64::MAX, 9999, 10000).unwrap(),
+            1844489958375117
+        );
+        assert_eq!(
+            Calculator::get_amount_out(u64::MAX, u64::MAX, u64::MAX, u64::MAX, u64::MAX).unwrap(),
+            0
+        );
+        assert_eq!(
+            Calculator::get_amount_out(u64::MAX, 0, u64::MAX, u64::MAX - 1, u64::MAX).unwrap(),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn test_get_amount_out_rounding() {
+        let reserve = 1_000_000_000;
+        // fee below half a unit rounds to zero
+        assert_eq!(
+            Calculator::get_amount_out(199, reserve, reserve, 25, 10000).unwrap(),
+            198
+        );
+        // exactly half a unit rounds up
+        assert_eq!(
+            Calculator::get_amount_out(200, reserve, reserve, 25, 10000).unwrap(),
+            198
+        );
+        // any remainder above one unit rounds up
+        assert_eq!(
+            Calculator::get_amount_out(400, reserve, reserve, 25, 10000).unwrap(),
+            398
+        );
+        assert_eq!(
+            Calculator::get_amount_out(401, reserve, reserve, 25, 10000).unwrap(),
+            398
+        );
+        assert_eq!(
+            Calculator::get_amount_out(1, reserve, reserve, 25, 10000).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_get_amount_out_invalid() {
+        assert_eq!(
+            Calculator::get_amount_out(0, 0, 1000, 25, 10000),
+            Err(AmmError::CheckedDivOverflow)
+        );
+        assert_eq!(
+            Calculator::get_amount_out(100, 1000, 1000, 25, 0),
+            Err(AmmError::CheckedDivOverflow)
+        );
+        assert_eq!(
+            Calculator::get_amount_out(100, 1000, 1000, 2, 1),
+            Err(AmmError::CheckedSubOverflow)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_get_amount_out_matches_swap_base_in(
+            amount_in in 1..u64::MAX,
+            coin in 1..u64::MAX,
+            pc in 1..u64::MAX,
+        ) {
+            let amount_out = Calculator::get_amount_out(amount_in, coin, pc, 25, 10000).unwrap();
+            let swap_fee = U128::from(amount_in)
+                .checked_mul(U128::from(25u64))
+                .unwrap()
+                .checked_ceil_div(U128::from(10000u64))
+                .unwrap()
+                .0;
+            let expected = Calculator::swap_token_amount_base_in(
+                U128::from(amount_in) - swap_fee,
+                pc.into(),
+                coin.into(),
+                SwapDirection::Coin2PC,
+            )
+            .as_u64();
+            prop_assert_eq!(amount_out, expected);
+            prop_assert!(amount_out < pc);
+        }
+    }
+}
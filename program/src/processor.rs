@@ -2291,6 +2291,9 @@ impl Processor {
         {
             return Err(AmmError::InvalidUserToken.into());
         }
+        if *user_source_info.key == *user_destination_info.key {
+            return Err(AmmError::InvalidUserToken.into());
+        }
 
         let amm_coin_vault =
             Self::unpack_token_account(&amm_coin_vault_info, spl_token_program_id)?;
@@ -2705,6 +2708,9 @@ impl Processor {
         {
             return Err(AmmError::InvalidUserToken.into());
         }
+        if *user_source_info.key == *user_destination_info.key {
+            return Err(AmmError::InvalidUserToken.into());
+        }
 
         let amm_coin_vault =
             Self::unpack_token_account(&amm_coin_vault_info, spl_token_program_id)?;
@@ -6051,10 +6057,7 @@ impl Processor {
         }
         let (orders_account, rest) = accounts.split_first().unwrap();
         let mut orders = SequencerOrders::load_mut_checked(orders_account, program_id)?;
-        if swap.order_index != orders.next_index {
-            return Err(AmmError::InvalidInput.into());
-        }
-        orders.next_index = orders.next_index.checked_add(1).ok_or(AmmError::InvalidInput)?;
+        orders.check_and_advance(swap.order_index)?;
         Self::process_swap_base_in(
             program_id,
             rest,
@@ -6075,10 +6078,7 @@ impl Processor {
         }
         let (orders_account, rest) = accounts.split_first().unwrap();
         let mut orders = SequencerOrders::load_mut_checked(orders_account, program_id)?;
-        if swap.order_index != orders.next_index {
-            return Err(AmmError::InvalidInput.into());
-        }
-        orders.next_index = orders.next_index.checked_add(1).ok_or(AmmError::InvalidInput)?;
+        orders.check_and_advance(swap.order_index)?;
         Self::process_swap_base_out(
             program_id,
             rest,
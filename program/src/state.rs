@@ -4,6 +4,7 @@ use crate::{error::AmmError, math::Calculator};
 use serum_dex::state::ToAlignedBytes;
 use solana_program::{
     account_info::AccountInfo,
+    msg,
     program_error::ProgramError,
     program_pack::{IsInitialized, Pack, Sealed},
     pubkey::Pubkey,
@@ -1057,6 +1058,25 @@ impl SequencerOrders {
         let data = Self::load(account)?;
         Ok(data)
     }
+
+    /// Consume `order_index` if it is the next expected order.
+    /// A freshly submitted order list starts at index 0.
+    #[inline]
+    pub fn check_and_advance(&mut self, order_index: u64) -> Result<(), ProgramError> {
+        if order_index != self.next_index {
+            msg!(
+                "order_index mismatch: input:{}, expected:{}",
+                order_index,
+                identity(self.next_index)
+            );
+            return Err(AmmError::InvalidInput.into());
+        }
+        self.next_index = self
+            .next_index
+            .checked_add(1)
+            .ok_or(AmmError::InvalidInput)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1584,4 +1604,64 @@ mod test {
         let unpack_free_slot_bits = unpack_data.free_slot_bits;
         assert_eq!(free_slot_bits, unpack_free_slot_bits);
     }
+
+    #[test]
+    fn test_sequencer_orders_first_index() {
+        // a freshly submitted order list expects index 0 first
+        let mut orders = SequencerOrders::default();
+        assert_eq!(
+            orders.check_and_advance(1),
+            Err(AmmError::InvalidInput.into())
+        );
+        assert_eq!(identity(orders.next_index), 0);
+        orders.check_and_advance(0).unwrap();
+        assert_eq!(identity(orders.next_index), 1);
+        assert_eq!(
+            orders.check_and_advance(0),
+            Err(AmmError::InvalidInput.into())
+        );
+        orders.check_and_advance(1).unwrap();
+        assert_eq!(identity(orders.next_index), 2);
+    }
+
+    #[test]
+    fn test_sequencer_orders_index_overflow() {
+        let mut orders = SequencerOrders::default();
+        orders.next_index = u64::MAX;
+        assert_eq!(
+            orders.check_and_advance(u64::MAX),
+            Err(AmmError::InvalidInput.into())
+        );
+        assert_eq!(identity(orders.next_index), u64::MAX);
+    }
+
+    #[test]
+    fn test_sequencer_orders_advance_persists_to_account() {
+        let program_id = Pubkey::new_unique();
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![0u8; size_of::<SequencerOrders>()];
+        let orders_info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &program_id,
+            false,
+            0,
+        );
+        {
+            let mut orders = SequencerOrders::load_mut_checked(&orders_info, &program_id).unwrap();
+            orders.next_index = 7;
+            orders.check_and_advance(7).unwrap();
+        }
+
+        let mut orders = SequencerOrders::load_mut_checked(&orders_info, &program_id).unwrap();
+        assert_eq!(identity(orders.next_index), 8);
+        assert_eq!(
+            orders.check_and_advance(7),
+            Err(AmmError::InvalidInput.into())
+        );
+    }
 }